@@ -0,0 +1,132 @@
+//! A driver-abstraction layer that hides the concrete database client behind a
+//! single [`DatabaseClient`] trait.
+//!
+//! Historically `Row`, `ColumnType`, `Type` and every `QueryParameters` impl
+//! hardcoded exactly the two supported backends (`tokio_postgres` and
+//! `tiberius`) through `downcast_ref` chains. Modelled after the way `sqlx`
+//! splits each database into its own crate and Spin hides its PG client behind a
+//! single `Client` trait, this trait owns `query`/`execute` plus the associated
+//! row/column/param representations so a new backend (e.g. `mysql_async` or
+//! `sqlx-sqlite`) becomes a self-contained module instead of a cross-cutting edit.
+
+use std::error::Error;
+
+/// Boxed, thread-safe error returned by every driver operation, matching the
+/// error shape already used across the CRUD layer.
+pub type DriverError = Box<dyn Error + Send + Sync + 'static>;
+
+/// The contract a database backend must satisfy to be usable by Canyon.
+///
+/// Implementors live in their own module (see [`postgres`] and [`sqlserver`])
+/// and own the translation between Canyon's generic query surface and the native
+/// client, so adding a backend is a self-contained module rather than a new arm
+/// threaded through every call site. Statement execution dispatches here and
+/// column decoding dispatches through each backend's own `Row` impl
+/// (`Row::column_metadata`); typed value decode (`get`/`try_get`) stays a generic
+/// free function because it is generic over the output type and a `&dyn Row`
+/// carrying a generic method would not be object-safe.
+#[async_trait::async_trait]
+pub trait DatabaseClient: Send + Sync {
+    /// The backend's native row type, yielded by [`DatabaseClient::query`].
+    type Row;
+    /// The backend's native column metadata type.
+    type Column;
+    /// The parameter representation bound into a prepared statement.
+    type Param: ?Sized;
+
+    /// Runs a statement expected to return rows.
+    async fn query(
+        &mut self,
+        stmt: &str,
+        params: &[&Self::Param],
+    ) -> Result<Vec<Self::Row>, DriverError>;
+
+    /// Runs a statement that mutates data, returning the affected row count.
+    async fn execute(
+        &mut self,
+        stmt: &str,
+        params: &[&Self::Param],
+    ) -> Result<u64, DriverError>;
+}
+
+/// The `tokio_postgres` backend.
+#[cfg(feature = "tokio-postgres")]
+pub mod postgres {
+    use super::{DatabaseClient, DriverError};
+    use tokio_postgres::{types::ToSql, Client, Row};
+
+    /// Wraps a live `tokio_postgres` client so it can be driven through the
+    /// generic [`DatabaseClient`] surface.
+    pub struct PostgresClient {
+        pub client: Client,
+    }
+
+    #[async_trait::async_trait]
+    impl DatabaseClient for PostgresClient {
+        type Row = Row;
+        type Column = tokio_postgres::Column;
+        type Param = (dyn ToSql + Sync);
+
+        async fn query(
+            &mut self,
+            stmt: &str,
+            params: &[&Self::Param],
+        ) -> Result<Vec<Self::Row>, DriverError> {
+            Ok(self.client.query(stmt, params).await?)
+        }
+
+        async fn execute(
+            &mut self,
+            stmt: &str,
+            params: &[&Self::Param],
+        ) -> Result<u64, DriverError> {
+            Ok(self.client.execute(stmt, params).await?)
+        }
+    }
+}
+
+/// The `tiberius` (SQL Server) backend.
+#[cfg(feature = "tiberius")]
+pub mod sqlserver {
+    use super::{DatabaseClient, DriverError};
+    use async_std::net::TcpStream;
+    use tiberius::{Client, ColumnData, Row};
+
+    /// Wraps a live `tiberius` client so it can be driven through the generic
+    /// [`DatabaseClient`] surface.
+    pub struct SqlServerClient {
+        pub client: Client<TcpStream>,
+    }
+
+    #[async_trait::async_trait]
+    impl DatabaseClient for SqlServerClient {
+        type Row = Row;
+        type Column = tiberius::Column;
+        // The parameter is a `ColumnData`, mirroring
+        // `QueryParameters::as_sqlserver_param`, so the CRUD layer binds exactly
+        // the value that method already produces instead of a closure.
+        type Param = ColumnData<'static>;
+
+        async fn query(
+            &mut self,
+            stmt: &str,
+            params: &[&Self::Param],
+        ) -> Result<Vec<Self::Row>, DriverError> {
+            let args: Vec<&dyn tiberius::ToSql> =
+                params.iter().map(|c| *c as &dyn tiberius::ToSql).collect();
+            let stream = self.client.query(stmt, &args).await?;
+            Ok(stream.into_first_result().await?)
+        }
+
+        async fn execute(
+            &mut self,
+            stmt: &str,
+            params: &[&Self::Param],
+        ) -> Result<u64, DriverError> {
+            let args: Vec<&dyn tiberius::ToSql> =
+                params.iter().map(|c| *c as &dyn tiberius::ToSql).collect();
+            let result = self.client.execute(stmt, &args).await?;
+            Ok(result.total())
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use crate::{
     crud::{CrudOperations, Transaction},
+    error::CanyonSqlError,
     mapper::RowMapper,
 };
 use canyon_connection::{
@@ -9,6 +10,10 @@ use canyon_connection::{
     tokio_postgres::{types::ToSql, self}
 };
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
 use std::{fmt::Debug, any::Any};
 
 /// Created for retrieve the field's name of a field of a struct, giving
@@ -48,19 +53,16 @@ where
 /// It's a generification to convert everything to a string representation
 /// in SQL syntax, so the clauses can use any value to make filters
 ///
-/// Ex:
-/// `SELECT * FROM some_table WHERE id = '2'`
+/// [`FieldValueIdentifier::value`] returns a `"<column> <value>"` pair: the
+/// column-qualified name the predicate filters on, a space, and the value
+/// already formatted as an SQL literal (numbers bare, strings quoted). The
+/// QueryBuilder splits that pair on the first space to build `<column> <op>
+/// <value>`, e.g. `WHERE league.id = 1`.
 ///
-/// That '2' it's extracted from some enum that implements [`FieldValueIdentifier`],
-/// where usually the variant w'd be something like:
-///
-/// ```
-/// pub enum Enum {
-///     IntVariant(i32)
-/// }
-/// ```
-/// so, the `.value(self)` method it's called over `self`, gets the value for that variant
-/// (or another specified in the logic) and returns that value as an [`String`]
+/// The generated `<Type>Fields` enum produces this pair from the selected
+/// variant; the bare [`&str`] implementor is for passing a pre-formatted
+/// `"<column> <value>"` fragment by hand, so both implementors yield a valid
+/// predicate.
 pub trait FieldValueIdentifier<T>
 where
     T: Transaction<T> + CrudOperations<T> + RowMapper<T> + Debug,
@@ -72,6 +74,8 @@ impl<T> FieldValueIdentifier<T> for &str
 where
     T: Transaction<T> + CrudOperations<T> + RowMapper<T> + Debug,
 {
+    /// Passes the caller-supplied `"<column> <value>"` fragment through verbatim,
+    /// so a hand-written `"league.id 1"` produces `WHERE league.id = 1`.
     fn value(self) -> String {
         self.to_string()
     }
@@ -102,17 +106,47 @@ pub trait ForeignKeyable<T> {
 pub trait InClauseValues: ToSql + ToString {}
 
 /// Generic abstraction to represent any of the Row types
-/// from the client crates
+/// from the client crates.
+///
+/// Each backend owns the translation of its native column metadata into
+/// Canyon's [`Column`] through [`Row::column_metadata`], so adding a backend is
+/// a new `impl Row` rather than a new arm in a central `downcast_ref` chain.
+/// Typed value decode (`get`/`try_get`) can't be dispatched here — it is generic
+/// over the `Output` type and `&dyn Row` would stop being object-safe — so it
+/// keeps recovering the concrete row via [`Row::as_any`].
 pub trait Row {
     fn as_any(&self) -> &dyn Any;
-    
+
+    /// The row's columns, in order, described in Canyon's backend-agnostic
+    /// [`Column`] terms.
+    fn column_metadata(&self) -> Vec<Column<'_>>;
 }
 impl Row for tokio_postgres::Row {
     fn as_any(&self) -> &dyn Any { self }
+
+    fn column_metadata(&self) -> Vec<Column<'_>> {
+        self.columns()
+            .iter()
+            .map(|c| Column {
+                name: c.name(),
+                type_: ColumnType::Postgres(c.type_().to_owned()),
+            })
+            .collect()
+    }
 }
 
 impl Row for tiberius::Row {
     fn as_any(&self) -> &dyn Any { self }
+
+    fn column_metadata(&self) -> Vec<Column<'_>> {
+        self.columns()
+            .iter()
+            .map(|c| Column {
+                name: c.name(),
+                type_: ColumnType::SqlServer(c.column_type()),
+            })
+            .collect()
+    }
 }
 
 pub struct Column<'a> {
@@ -153,80 +187,117 @@ pub enum ColumnType {
 pub trait RowOperations {
     /// Abstracts the different forms of use the common `get` row
     /// function or method dynamically no matter what are the origin
-    /// type from any database client provider
+    /// type from any database client provider.
+    ///
+    /// Panics on a type mismatch or an unexpected `NULL`; prefer
+    /// [`RowOperations::try_get`] on any path where failure should be handled
+    /// gracefully instead of aborting the process.
     fn get<'a, Output>(&'a self, col_name: &str) -> Output
         where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>;
 
-    fn get_opt<'a, Output>(&'a self, col_name: &str) -> Option<Output> 
+    fn get_opt<'a, Output>(&'a self, col_name: &str) -> Option<Output>
         where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>;
 
     fn columns<'a>(&'a self) -> Vec<Column>;
+
+    /// Fallible counterpart of [`RowOperations::get`]. Surfaces the underlying
+    /// driver conversion error, a "column not found" or an "unexpected NULL"
+    /// [`CanyonSqlError`] instead of panicking.
+    fn try_get<'a, Output>(&'a self, col_name: &str) -> Result<Output, CanyonSqlError>
+        where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>;
+
+    /// Fallible counterpart of [`RowOperations::get_opt`].
+    fn try_get_opt<'a, Output>(&'a self, col_name: &str) -> Result<Option<Output>, CanyonSqlError>
+        where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>;
+
+    /// Fallible counterpart of [`RowOperations::columns`].
+    fn try_columns<'a>(&'a self) -> Result<Vec<Column>, CanyonSqlError>;
 }
 
 impl RowOperations for &dyn Row {
-    fn get<'a, Output>(&'a self, col_name: &str) -> Output 
+    fn get<'a, Output>(&'a self, col_name: &str) -> Output
         where Output: tokio_postgres::types::FromSql<'a>  + tiberius::FromSql<'a>
     {
-        match self.as_any().downcast_ref::<tokio_postgres::Row>() {
-            Some(row) => { return row.get::<&str, Output>(col_name); },
-            None => (),
-        };
-        match self.as_any().downcast_ref::<tiberius::Row>() {
-            Some(row) => { 
-                return row.get::<Output, &str>(col_name)
-                    .expect("Failed to obtain a row in the MSSQL migrations"); 
-            },
-            None => (),
-        };
-        panic!()
+        self.try_get(col_name)
+            .expect("Failed to obtain a row in the MSSQL migrations")
     }
 
     fn columns<'a>(&'a self) -> Vec<Column>
     {
-        let mut cols = vec![];
-
-        if self.as_any().is::<tokio_postgres::Row>() {
-            self.as_any().downcast_ref::<tokio_postgres::Row>()
-                .expect("Not a tokio postgres Row for column")
-                .columns()
-                .into_iter()
-                .for_each(|c| cols.push(
-                    Column {
-                        name: c.name(),
-                        type_: ColumnType::Postgres(c.type_().to_owned())
-                    }
-                ))
-        } else {
-            self.as_any().downcast_ref::<tiberius::Row>()
-                .expect("Not a Tiberius Row for column")
-                .columns()
-                .into_iter()
-                .for_each(|c| cols.push(
-                    Column {
-                        name: c.name(),
-                        type_: ColumnType::SqlServer(c.column_type())
-                    }
-                ))
-        };
-
-        cols
-    }
-
-    fn get_opt<'a, Output>(&'a self, col_name: &str) -> Option<Output> 
-        where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a> 
+        self.try_columns()
+            .expect("Failed to read the columns of a row")
+    }
+
+    fn get_opt<'a, Output>(&'a self, col_name: &str) -> Option<Output>
+        where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>
+    {
+        self.try_get_opt(col_name)
+            .expect("Failed to obtain a row in the MSSQL migrations")
+    }
+
+    fn try_get<'a, Output>(&'a self, col_name: &str) -> Result<Output, CanyonSqlError>
+        where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>
     {
-        match self.as_any().downcast_ref::<tokio_postgres::Row>() {
-            Some(row) => { return row.get::<&str, Option<Output>>(col_name); },
-            None => (),
-        };
-        match self.as_any().downcast_ref::<tiberius::Row>() {
-            Some(row) => { 
-                return row.try_get::<Output, &str>(col_name)
-                    .expect("Failed to obtain a row in the MSSQL migrations"); 
-            },
-            None => (),
-        };
-        panic!()
+        if let Some(row) = self.as_any().downcast_ref::<tokio_postgres::Row>() {
+            // `tokio_postgres` matches a column name exactly, then ASCII
+            // case-insensitively, so missing both means the column is absent.
+            if !row.columns().iter().any(|c| c.name().eq_ignore_ascii_case(col_name)) {
+                return Err(CanyonSqlError::ColumnNotFound(col_name.to_owned()));
+            }
+            return row.try_get::<&str, Output>(col_name).map_err(|e| {
+                let was_null = std::error::Error::source(&e)
+                    .map_or(false, |s| s.is::<tokio_postgres::types::WasNull>());
+                if was_null {
+                    CanyonSqlError::UnexpectedNull(col_name.to_owned())
+                } else {
+                    CanyonSqlError::RowDecode(Box::new(e))
+                }
+            });
+        }
+        if let Some(row) = self.as_any().downcast_ref::<tiberius::Row>() {
+            if !row.columns().iter().any(|c| c.name() == col_name) {
+                return Err(CanyonSqlError::ColumnNotFound(col_name.to_owned()));
+            }
+            return match row.try_get::<Output, &str>(col_name) {
+                Ok(Some(value)) => Ok(value),
+                Ok(None) => Err(CanyonSqlError::UnexpectedNull(col_name.to_owned())),
+                Err(e) => Err(CanyonSqlError::RowDecode(Box::new(e))),
+            };
+        }
+        Err(CanyonSqlError::ColumnNotFound(col_name.to_owned()))
+    }
+
+    fn try_get_opt<'a, Output>(&'a self, col_name: &str) -> Result<Option<Output>, CanyonSqlError>
+        where Output: tokio_postgres::types::FromSql<'a> + tiberius::FromSql<'a>
+    {
+        if let Some(row) = self.as_any().downcast_ref::<tokio_postgres::Row>() {
+            if !row.columns().iter().any(|c| c.name().eq_ignore_ascii_case(col_name)) {
+                return Err(CanyonSqlError::ColumnNotFound(col_name.to_owned()));
+            }
+            return row.try_get::<&str, Option<Output>>(col_name)
+                .map_err(|e| CanyonSqlError::RowDecode(Box::new(e)));
+        }
+        if let Some(row) = self.as_any().downcast_ref::<tiberius::Row>() {
+            if !row.columns().iter().any(|c| c.name() == col_name) {
+                return Err(CanyonSqlError::ColumnNotFound(col_name.to_owned()));
+            }
+            // `tiberius`' `try_get` already yields `Option<Output>`, with `None`
+            // for a SQL `NULL`; pass that through so a NULL becomes `Ok(None)`,
+            // matching the `Option<Output>` decode on the `tokio_postgres` arm
+            // above rather than erroring.
+            return match row.try_get::<Output, &str>(col_name) {
+                Ok(value) => Ok(value),
+                Err(e) => Err(CanyonSqlError::RowDecode(Box::new(e))),
+            };
+        }
+        Err(CanyonSqlError::ColumnNotFound(col_name.to_owned()))
+    }
+
+    fn try_columns<'a>(&'a self) -> Result<Vec<Column>, CanyonSqlError>
+    {
+        // Column metadata dispatches straight through the `Row` trait, so a new
+        // backend supplies its columns from its own `impl Row` with no edit here.
+        Ok((**self).column_metadata())
     }
 }
 
@@ -589,3 +660,236 @@ impl<'a> QueryParameters<'_> for Option<DateTime<Utc>> {
         self.into_sql()
     }
 }
+
+impl<'a> QueryParameters<'a> for bool {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Bit(Some(*self))
+    }
+}
+impl<'a> QueryParameters<'a> for &bool {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Bit(Some(**self))
+    }
+}
+impl<'a> QueryParameters<'a> for Option<bool> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Bit(*self)
+    }
+}
+impl<'a> QueryParameters<'a> for Option<&bool> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Bit(self.copied())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<'a> QueryParameters<'a> for Uuid {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Guid(Some(*self))
+    }
+}
+#[cfg(feature = "uuid")]
+impl<'a> QueryParameters<'a> for &Uuid {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Guid(Some(**self))
+    }
+}
+#[cfg(feature = "uuid")]
+impl<'a> QueryParameters<'a> for Option<Uuid> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Guid(*self)
+    }
+}
+#[cfg(feature = "uuid")]
+impl<'a> QueryParameters<'a> for Option<&Uuid> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Guid(self.copied())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<'a> QueryParameters<'a> for Decimal {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        self.into_sql()
+    }
+}
+#[cfg(feature = "rust_decimal")]
+impl<'a> QueryParameters<'a> for &Decimal {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        self.into_sql()
+    }
+}
+#[cfg(feature = "rust_decimal")]
+impl<'a> QueryParameters<'a> for Option<Decimal> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        self.into_sql()
+    }
+}
+
+/// JSON payloads. On `PostgreSQL` `postgres-types` serializes
+/// [`serde_json::Value`] straight to `jsonb`. `tiberius` has no JSON column, so
+/// the document is rendered to its textual form and bound as `nvarchar`.
+#[cfg(feature = "serde_json")]
+impl<'a> QueryParameters<'a> for serde_json::Value {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(std::borrow::Cow::Owned(self.to_string())))
+    }
+}
+#[cfg(feature = "serde_json")]
+impl<'a> QueryParameters<'a> for &serde_json::Value {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::String(Some(std::borrow::Cow::Owned(self.to_string())))
+    }
+}
+#[cfg(feature = "serde_json")]
+impl<'a> QueryParameters<'a> for Option<serde_json::Value> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        match self {
+            Some(value) => ColumnData::String(Some(std::borrow::Cow::Owned(value.to_string()))),
+            None => ColumnData::String(None),
+        }
+    }
+}
+
+/// Byte buffers (`bytea` on `PostgreSQL`, `varbinary` on SQL Server). `u8` does
+/// not implement [`ToSql`], so `Vec<u8>`/`&[u8]` fall outside the generic
+/// collection impls and need their own binding: `tokio_postgres` special-cases
+/// byte slices straight to `bytea`, while `tiberius` carries them in a
+/// [`ColumnData::Binary`].
+impl<'a> QueryParameters<'a> for Vec<u8> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Binary(Some(std::borrow::Cow::Borrowed(self.as_slice())))
+    }
+}
+impl<'a> QueryParameters<'a> for &'a [u8] {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        ColumnData::Binary(Some(std::borrow::Cow::Borrowed(self)))
+    }
+}
+impl<'a> QueryParameters<'a> for Option<Vec<u8>> {
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        match self {
+            Some(bytes) => ColumnData::Binary(Some(std::borrow::Cow::Borrowed(bytes.as_slice()))),
+            None => ColumnData::Binary(None),
+        }
+    }
+}
+
+/// Collection parameters, so a whole Rust slice can be bound as a single query
+/// argument instead of hand-building one placeholder per element.
+///
+/// On `PostgreSQL`, `tokio_postgres` already implements [`ToSql`] for slices and
+/// [`Vec`], producing an `ANYARRAY`, so `WHERE id = ANY($1)` binds directly and
+/// an array column round-trips as a single bind.
+///
+/// `tiberius` has no native array type: there is no `ColumnData` variant that
+/// represents a list. When a whole collection is bound as a single SQL Server
+/// parameter the elements are joined with commas and sent as a single
+/// `nvarchar`, which the server can split back apart (e.g. through
+/// `STRING_SPLIT($1, ',')`). This keeps a parameter-binding accessor total:
+/// callers that need one placeholder per element on SQL Server should reach for
+/// [`query_elements::query_builder::QueryBuilder::in_clause`] instead.
+///
+/// [`query_elements::query_builder::QueryBuilder::in_clause`]: crate::query_elements::query_builder::QueryBuilder::in_clause
+impl<'a, T> QueryParameters<'a> for Vec<T>
+where
+    T: ToSql + ToString + Sync + Send + Debug,
+{
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        let joined = self
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        ColumnData::String(Some(std::borrow::Cow::Owned(joined)))
+    }
+}
+impl<'a, T> QueryParameters<'a> for &'a [T]
+where
+    T: ToSql + ToString + Sync + Send + Debug,
+{
+    fn as_postgres_param(&self) -> &(dyn ToSql + Sync) {
+        self
+    }
+
+    fn as_sqlserver_param(&self) -> ColumnData<'_> {
+        let joined = self
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        ColumnData::String(Some(std::borrow::Cow::Owned(joined)))
+    }
+}
@@ -0,0 +1,40 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// The error surfaced by the fallible row-decoding API on [`RowOperations`].
+///
+/// It mirrors the typed row-decode errors returned by `sqlx`: a type mismatch,
+/// a missing column or a `NULL` read into a non-optional slot no longer aborts
+/// the process, but is surfaced for the caller to handle.
+///
+/// [`RowOperations`]: crate::bounds::RowOperations
+#[derive(Debug)]
+pub enum CanyonSqlError {
+    /// The requested column does not exist in the row.
+    ColumnNotFound(String),
+    /// A non-optional column held a `NULL` value.
+    UnexpectedNull(String),
+    /// The underlying driver failed to convert the column to the target type.
+    RowDecode(Box<dyn Error + Send + Sync + 'static>),
+}
+
+impl Display for CanyonSqlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanyonSqlError::ColumnNotFound(col) => write!(f, "column `{col}` not found in row"),
+            CanyonSqlError::UnexpectedNull(col) => {
+                write!(f, "unexpected NULL in non-optional column `{col}`")
+            }
+            CanyonSqlError::RowDecode(e) => write!(f, "failed to decode row value: {e}"),
+        }
+    }
+}
+
+impl Error for CanyonSqlError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CanyonSqlError::RowDecode(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
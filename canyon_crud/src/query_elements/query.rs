@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::crud::{CrudOperations, Transaction};
+use crate::mapper::RowMapper;
+use crate::query_elements::query_builder::QueryBuilder;
+
+/// The seed of a runtime-built statement.
+///
+/// The macro-generated `*_query` associated functions (e.g. `update_query`)
+/// hand a statement prefix such as `UPDATE leagues` to [`Query::generate`],
+/// which returns a [`QueryBuilder`] ready to accumulate predicates, ordering and
+/// pagination before [`QueryBuilder::query`] runs it.
+pub struct Query<'a, T>
+where
+    T: Transaction<T> + CrudOperations<T> + RowMapper<T> + Debug,
+{
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Query<'a, T>
+where
+    T: Transaction<T> + CrudOperations<T> + RowMapper<T> + Debug,
+{
+    /// Opens a [`QueryBuilder`] over `query` (a statement prefix) bound to
+    /// `datasource_name`.
+    pub fn generate(query: String, datasource_name: &'a str) -> QueryBuilder<'a, T> {
+        QueryBuilder::new(query, datasource_name)
+    }
+}
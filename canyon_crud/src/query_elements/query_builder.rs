@@ -0,0 +1,292 @@
+use std::marker::PhantomData;
+
+use crate::bounds::{FieldIdentifier, FieldValueIdentifier, QueryParameters};
+use crate::crud::{CrudOperations, Transaction};
+use crate::mapper::RowMapper;
+use std::fmt::Debug;
+
+/// The comparison operator used on a predicate's right-hand side.
+#[derive(Debug, Clone, Copy)]
+pub enum Comp {
+    /// `=`
+    Eq,
+    /// `<>`
+    Neq,
+    /// `>`
+    Gt,
+    /// `>=`
+    GtEq,
+    /// `<`
+    Lt,
+    /// `<=`
+    LtEq,
+}
+
+impl Comp {
+    fn as_operator(&self) -> &'static str {
+        match self {
+            Comp::Eq => "=",
+            Comp::Neq => "<>",
+            Comp::Gt => ">",
+            Comp::GtEq => ">=",
+            Comp::Lt => "<",
+            Comp::LtEq => "<=",
+        }
+    }
+}
+
+/// The direction of an `ORDER BY` clause.
+#[derive(Debug, Clone, Copy)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+impl Order {
+    fn as_keyword(&self) -> &'static str {
+        match self {
+            Order::Asc => "ASC",
+            Order::Desc => "DESC",
+        }
+    }
+}
+
+/// A runtime builder over a `SELECT`/`UPDATE` statement prefix. It appends only
+/// `WHERE`, `ORDER BY`, `LIMIT` and `OFFSET` clauses; it has no way to add the
+/// `SET` list of an `UPDATE`.
+///
+/// Scalar predicates carry their already type-formatted literal inline (matching
+/// the documented `WHERE league.id = 1` output), while set-membership and
+/// `LIKE` values are pushed, in order, into a single `params` buffer so their
+/// `$n` placeholders stay aligned and those fragments remain injection-safe. A
+/// single `where_clause` opens the predicate list; `and_clause`/`or_clause` chain
+/// additional predicates, `begin_group`/`end_group` express parenthesization
+/// (so `a AND (b OR c)` is expressible), and `order_by`/`limit`/`offset` refine
+/// the result set.
+pub struct QueryBuilder<'a, T>
+where
+    T: Transaction<T> + CrudOperations<T> + RowMapper<T> + Debug,
+{
+    query: String,
+    predicates: String,
+    params: Vec<String>,
+    order: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    datasource_name: &'a str,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> QueryBuilder<'a, T>
+where
+    T: Transaction<T> + CrudOperations<T> + RowMapper<T> + Debug,
+{
+    /// Creates a builder over an already-generated statement prefix, e.g.
+    /// `SELECT * FROM leagues`.
+    pub fn new(query: String, datasource_name: &'a str) -> Self {
+        Self {
+            query,
+            predicates: String::new(),
+            params: Vec::new(),
+            order: None,
+            limit: None,
+            offset: None,
+            datasource_name,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Opens the predicate list with the first `WHERE` filter.
+    pub fn where_clause<F>(mut self, column: F, comp: Comp) -> Self
+    where
+        F: FieldValueIdentifier<T>,
+    {
+        self.push_predicate(None, column.value(), comp);
+        self
+    }
+
+    /// Appends an `AND <predicate>`.
+    pub fn and_clause<F>(mut self, column: F, comp: Comp) -> Self
+    where
+        F: FieldValueIdentifier<T>,
+    {
+        self.push_predicate(Some("AND"), column.value(), comp);
+        self
+    }
+
+    /// Appends an `OR <predicate>`.
+    pub fn or_clause<F>(mut self, column: F, comp: Comp) -> Self
+    where
+        F: FieldValueIdentifier<T>,
+    {
+        self.push_predicate(Some("OR"), column.value(), comp);
+        self
+    }
+
+    /// Opens a parenthesized group, optionally joined to the running predicate.
+    pub fn begin_group(mut self, joiner: Option<&str>) -> Self {
+        if !self.predicates.is_empty() {
+            if let Some(joiner) = joiner {
+                self.predicates.push_str(&format!(" {joiner}"));
+            }
+        }
+        self.predicates.push_str(" (");
+        self
+    }
+
+    /// Closes a parenthesized group opened by [`QueryBuilder::begin_group`].
+    pub fn end_group(mut self) -> Self {
+        self.predicates.push(')');
+        self
+    }
+
+    /// `<column> IN (...)` against a set of values.
+    pub fn in_clause<F, V>(self, column: F, values: &[V]) -> Self
+    where
+        F: FieldIdentifier<T>,
+        V: ToString,
+    {
+        self.set_membership(column, values, false)
+    }
+
+    /// `<column> NOT IN (...)` against a set of values.
+    pub fn not_in_clause<F, V>(self, column: F, values: &[V]) -> Self
+    where
+        F: FieldIdentifier<T>,
+        V: ToString,
+    {
+        self.set_membership(column, values, true)
+    }
+
+    /// `<column> LIKE <pattern>`.
+    pub fn like_clause<F>(mut self, column: F, pattern: &str) -> Self
+    where
+        F: FieldIdentifier<T>,
+    {
+        self.chain_connective();
+        self.params.push(pattern.to_owned());
+        let placeholder = self.params.len();
+        self.predicates
+            .push_str(&format!(" {} LIKE ${placeholder}", column.field_name_as_str()));
+        self
+    }
+
+    /// Adds an `ORDER BY <column> <direction>` clause.
+    pub fn order_by<F>(mut self, column: F, order: Order) -> Self
+    where
+        F: FieldIdentifier<T>,
+    {
+        self.order = Some(format!("{} {}", column.field_name_as_str(), order.as_keyword()));
+        self
+    }
+
+    /// Adds a `LIMIT n` clause.
+    pub fn limit(mut self, n: i64) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Adds an `OFFSET n` clause.
+    pub fn offset(mut self, n: i64) -> Self {
+        self.offset = Some(n);
+        self
+    }
+
+    /// Executes the built statement against the builder's datasource, binding
+    /// every accumulated value through its [`QueryParameters`] impl and
+    /// deserializing the returned rows into `T`.
+    ///
+    /// Mirrors the infallible shape of `CrudOperations::find_all`: a driver
+    /// failure aborts rather than returning a `Result`, so call sites read
+    /// `League::find_all_query().where_clause(..).query().await -> Vec<League>`.
+    pub async fn query(self) -> Vec<T> {
+        let stmt = self.build();
+        let params: Vec<&dyn QueryParameters<'_>> = self
+            .params
+            .iter()
+            .map(|value| value as &dyn QueryParameters<'_>)
+            .collect();
+
+        let result = <T as Transaction<T>>::query(stmt, params.as_slice(), self.datasource_name)
+            .await
+            .expect("Failed to execute the statement built by the QueryBuilder");
+
+        result.get_entities()
+    }
+
+    /// Assembles the final SQL string. Used by [`QueryBuilder::query`] and
+    /// exposed for tests.
+    pub fn build(&self) -> String {
+        let mut stmt = self.query.clone();
+        if !self.predicates.trim().is_empty() {
+            stmt.push_str(" WHERE");
+            stmt.push_str(&self.predicates);
+        }
+        if let Some(order) = &self.order {
+            stmt.push_str(&format!(" ORDER BY {order}"));
+        }
+        if let Some(limit) = self.limit {
+            stmt.push_str(&format!(" LIMIT {limit}"));
+        }
+        if let Some(offset) = self.offset {
+            stmt.push_str(&format!(" OFFSET {offset}"));
+        }
+        stmt
+    }
+
+    /// Pushes a scalar predicate of the form `<column> <op> <value>`.
+    ///
+    /// [`FieldValueIdentifier::value`] yields a `"<column> <value>"` pair — the
+    /// generated `<Type>Fields` variant (e.g. `LeagueFields::id(1)`) renders the
+    /// column-qualified name followed by the already type-formatted literal, so
+    /// the fragment reads `league.id = 1`, matching the documented QueryBuilder
+    /// output. The literal is emitted inline rather than bound as a text `$n`,
+    /// since `value()` only carries a `String` and binding it would make the
+    /// driver reject an integer or boolean column on a type mismatch.
+    fn push_predicate(&mut self, joiner: Option<&str>, column_and_value: String, comp: Comp) {
+        if let Some(joiner) = joiner {
+            if !self.predicates.trim().is_empty() {
+                self.predicates.push_str(&format!(" {joiner}"));
+            }
+        }
+
+        let mut parts = column_and_value.splitn(2, ' ');
+        let column = parts.next().unwrap_or_default();
+        let value = parts.next().unwrap_or_default();
+
+        self.predicates
+            .push_str(&format!(" {column} {} {value}", comp.as_operator()));
+    }
+
+    /// Prepends the implicit `AND` connective before a new predicate fragment
+    /// when one is already present. The leading `WHERE` is added once, by
+    /// [`QueryBuilder::build`], so fragments never carry their own.
+    fn chain_connective(&mut self) {
+        if !self.predicates.trim().is_empty() {
+            self.predicates.push_str(" AND");
+        }
+    }
+
+    /// Shared implementation of `IN` / `NOT IN` set membership.
+    fn set_membership<F, V>(mut self, column: F, values: &[V], negated: bool) -> Self
+    where
+        F: FieldIdentifier<T>,
+        V: ToString,
+    {
+        self.chain_connective();
+        let keyword = if negated { "NOT IN" } else { "IN" };
+
+        let mut placeholders = Vec::with_capacity(values.len());
+        for value in values {
+            self.params.push(value.to_string());
+            placeholders.push(format!("${}", self.params.len()));
+        }
+
+        self.predicates.push_str(&format!(
+            " {} {keyword} ({})",
+            column.field_name_as_str(),
+            placeholders.join(", ")
+        ));
+        self
+    }
+}
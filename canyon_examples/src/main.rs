@@ -184,14 +184,14 @@ async fn _search_data_by_fk_example() {
     let related_tournaments_league: Option<League> = Tournament::belongs_to(&lec).await;
     println!("The related League as associated function: {:?}", &related_tournaments_league);
 
-    // TODO The reverse side of the FK should be implemented on League, not in tournament
-    // EX: League::search_related__tournaments(&lec)
-    // TODO Should be also an instance method? The lookage query w'd be based on the ID
-    // like -> SELECT * FROM TOURNAMENT t WHERE t.league = (value of the field)
     let tournaments_belongs_to_league: Vec<Tournament> = Tournament::search_by__league(&lec).await;
     println!("Tournament belongs to a league: {:?}", &tournaments_belongs_to_league);
 
-    // Method implementation over a League instance (prefered one)
-    let tournaments_by_reverse_foreign: Vec<Tournament> = Tournament::search_by__league(&lec).await;
+    // The reverse side of the FK, implemented as an instance method on the
+    // *referenced* entity (the preferred, one-to-many navigation). Deriving
+    // `Tournament` sees its own `#[foreign_key]` pointing at `League`'s primary
+    // key and emits `search_related_tournaments()` onto `League`, which runs
+    // `SELECT * FROM tournament WHERE league = <self.id>`.
+    let tournaments_by_reverse_foreign: Vec<Tournament> = lec.search_related_tournaments().await;
     println!("Tournament elements by reverse FK: {:?}", &tournaments_by_reverse_foreign);
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::derives::sql_type;
+
+/// Generates the TokenStream for the `#[canyon_enum]` derive.
+///
+/// For a fieldless Rust enum such as `enum Region { EuWest, SouthKorea }` this:
+///
+/// 1. contributes a `CREATE TYPE region AS ENUM ('EuWest','SouthKorea')`
+///    statement to Canyon's schema generation, and
+/// 2. implements the row encode/decode bounds so a `region: Region` field on an
+///    entity serializes to/from the DB enum in `find_all`, `insert` and
+///    `where_clause` filters.
+///
+/// The encode (`QueryParameters`) and decode (`FromSql`) halves are *not*
+/// re-implemented here: they are shared with [`CanyonSqlType`] through
+/// [`sql_type::generate_sql_type_tokens`], so an enum gets exactly one
+/// `QueryParameters`/`FromSql` implementation. `#[canyon_enum]` adds only the
+/// schema statement on top, and is the ENUM-specific alternative to deriving
+/// `CanyonSqlType` directly — an enum uses one or the other, never both.
+///
+/// Labels default to the variant identifier and are overridable per variant with
+/// `#[canyon_sql_type(label = "...")]` (the shared attribute), and a label read
+/// from the DB that matches no variant surfaces as a typed deserialization error
+/// rather than a panic.
+pub fn generate_canyon_enum_tokens(input: &DeriveInput) -> TokenStream {
+    let ty = &input.ident;
+
+    let Data::Enum(data) = &input.data else {
+        return syn::Error::new_spanned(input, "`#[canyon_enum]` can only be applied to enums")
+            .to_compile_error();
+    };
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new_spanned(
+                variant,
+                "`#[canyon_enum]` variants map to SQL ENUM labels and can't hold data",
+            )
+            .to_compile_error();
+        }
+    }
+
+    // The encode/decode round-trip and the label lookup come from the shared
+    // `CanyonSqlType` generator, so there is a single source of truth.
+    let round_trip = sql_type::generate_sql_type_tokens(input);
+
+    // The SQL type name is the snake_cased type identifier, matching Canyon's
+    // table-naming convention.
+    let type_name = to_snake_case(&ty.to_string());
+    let quoted_labels = data
+        .variants
+        .iter()
+        .map(sql_type::variant_label)
+        .map(|label| format!("'{label}'"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let create_type_stmt = format!("CREATE TYPE {type_name} AS ENUM ({quoted_labels})");
+
+    quote! {
+        #round_trip
+
+        impl #ty {
+            /// The `CREATE TYPE ... AS ENUM (...)` statement Canyon folds into
+            /// its schema generation for this enum.
+            pub fn canyon_enum_schema() -> &'static str {
+                #create_type_stmt
+            }
+        }
+    }
+}
+
+/// Lowercase snake_case conversion matching Canyon's identifier convention.
+fn to_snake_case(ident: &str) -> String {
+    let mut out = String::with_capacity(ident.len());
+    for (i, ch) in ident.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
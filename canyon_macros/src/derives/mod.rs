@@ -0,0 +1,2 @@
+pub mod canyon_enum;
+pub mod sql_type;
@@ -0,0 +1,185 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+/// Generates the TokenStream for the `#[derive(CanyonSqlType)]` macro, which
+/// maps a Rust type onto a PostgreSQL `ENUM` (or `CHECK`-constrained text) when
+/// applied to an enum, and onto a composite type when applied to a struct.
+///
+/// The expansion mirrors `postgres-types`' own `#[derive(ToSql, FromSql)]`
+/// contract: the emitted [`QueryParameters`] impl serializes an enum variant to
+/// its textual label (and, for SQL Server, to a `ColumnData::String`), while the
+/// generated `from_sql` bridge reads that same label back. Labels match
+/// case-sensitively; an unknown label surfaces as a `from_sql` error instead of
+/// a panic, so `RowOperations::try_get`/`try_get_opt` return it as an `Err`
+/// (the infallible `get`/`get_opt` still panic on it).
+pub fn generate_sql_type_tokens(input: &DeriveInput) -> TokenStream {
+    match &input.data {
+        Data::Enum(data) => enum_tokens(input, data),
+        Data::Struct(_) => composite_tokens(input),
+        Data::Union(_) => syn::Error::new_spanned(
+            input,
+            "`CanyonSqlType` can only be derived for enums and structs",
+        )
+        .to_compile_error(),
+    }
+}
+
+/// Maps a fieldless Rust enum to a PostgreSQL `ENUM`, rejecting variants that
+/// carry data (an SQL enum label is a bare string).
+fn enum_tokens(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream {
+    let ty = &input.ident;
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return syn::Error::new_spanned(
+                variant,
+                "`CanyonSqlType` enums map to SQL ENUM labels and can't hold data",
+            )
+            .to_compile_error();
+        }
+    }
+
+    // Variant identifier <-> textual label. The label defaults to the variant
+    // name and can be overridden per variant with `#[canyon_sql_type(label = "...")]`.
+    let to_label = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let label = variant_label(variant);
+        quote! { #ty::#ident => #label }
+    });
+    let from_label = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let label = variant_label(variant);
+        quote! { #label => Ok(#ty::#ident) }
+    });
+
+    quote! {
+        impl #ty {
+            /// The SQL enum label backing this variant.
+            fn as_sql_label(&self) -> &'static str {
+                match self {
+                    #(#to_label),*
+                }
+            }
+
+            /// Resolves a SQL enum label back into a variant, case-sensitively,
+            /// returning an error on an unknown label rather than panicking.
+            fn from_sql_label(label: &str)
+                -> Result<Self, Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                match label {
+                    #(#from_label),*,
+                    other => Err(
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidData,
+                            format!("unknown SQL enum label `{}` for `{}`", other, stringify!(#ty))
+                        ).into()
+                    )
+                }
+            }
+        }
+
+        impl<'a> canyon_sql::bounds::QueryParameters<'a> for #ty {
+            fn as_postgres_param(&self) -> &(dyn canyon_sql::bounds::ToSql + Sync) {
+                // The textual label is the wire form for a Postgres ENUM whose
+                // type name matches this Rust type.
+                self.as_sql_label()
+            }
+
+            fn as_sqlserver_param(&self) -> canyon_sql::bounds::ColumnData<'_> {
+                canyon_sql::bounds::ColumnData::String(
+                    Some(std::borrow::Cow::Borrowed(self.as_sql_label()))
+                )
+            }
+        }
+
+        // Decode bridge. Implementing both drivers' `FromSql` directly is what
+        // lets `RowOperations::get`/`get_opt` (bounded on
+        // `FromSql + tiberius::FromSql`) read the column straight back into the
+        // Rust enum, closing the round-trip with the encode half above.
+        impl<'a> canyon_sql::canyon_connection::tokio_postgres::types::FromSql<'a> for #ty {
+            fn from_sql(
+                ty: &canyon_sql::canyon_connection::tokio_postgres::types::Type,
+                raw: &'a [u8],
+            ) -> Result<Self, Box<dyn std::error::Error + Sync + std::marker::Send>> {
+                let label = <&str as canyon_sql::canyon_connection::tokio_postgres::types::FromSql>::from_sql(ty, raw)?;
+                #ty::from_sql_label(label)
+            }
+
+            fn accepts(_ty: &canyon_sql::canyon_connection::tokio_postgres::types::Type) -> bool {
+                // The label arrives as the enum's textual representation.
+                true
+            }
+        }
+
+        impl<'a> canyon_sql::canyon_connection::tiberius::FromSql<'a> for #ty {
+            fn from_sql(
+                value: &'a canyon_sql::canyon_connection::tiberius::ColumnData<'static>,
+            ) -> canyon_sql::canyon_connection::tiberius::Result<Option<Self>> {
+                match value {
+                    canyon_sql::canyon_connection::tiberius::ColumnData::String(Some(label)) => {
+                        #ty::from_sql_label(label)
+                            .map(Some)
+                            .map_err(|e| canyon_sql::canyon_connection::tiberius::error::Error::Conversion(
+                                e.to_string().into(),
+                            ))
+                    }
+                    _ => Ok(None),
+                }
+            }
+        }
+    }
+}
+
+/// Maps a Rust struct to a PostgreSQL composite type by binding the struct
+/// through its own `postgres-types` `ToSql` impl. No `FromSql` bridge is
+/// generated for structs, and binding one as a SQL Server parameter panics.
+fn composite_tokens(input: &DeriveInput) -> TokenStream {
+    let ty = &input.ident;
+
+    quote! {
+        impl<'a> canyon_sql::bounds::QueryParameters<'a> for #ty {
+            fn as_postgres_param(&self) -> &(dyn canyon_sql::bounds::ToSql + Sync) {
+                // Composite types round-trip through `postgres-types`' derived
+                // `ToSql`, which is required alongside `CanyonSqlType` on structs.
+                self
+            }
+
+            fn as_sqlserver_param(&self) -> canyon_sql::bounds::ColumnData<'_> {
+                // SQL Server has no composite type, and the `Debug` string was
+                // never a binding the driver could consume. Rather than quietly
+                // sending a bogus value, fail loudly so the unsupported mapping is
+                // caught at the call site.
+                panic!(
+                    "`{}` is a PostgreSQL composite type and cannot be bound as a SQL Server parameter",
+                    stringify!(#ty)
+                )
+            }
+        }
+    }
+}
+
+/// Reads the `#[canyon_sql_type(label = "...")]` override for a variant, falling
+/// back to the variant identifier when absent. Shared with the `#[canyon_enum]`
+/// derive, which builds its `CREATE TYPE ... AS ENUM (...)` labels from the same
+/// source so the schema and the round-trip never disagree.
+pub(crate) fn variant_label(variant: &syn::Variant) -> String {
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("canyon_sql_type") {
+            continue;
+        }
+        let mut label = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("label") {
+                let value = meta.value()?;
+                let lit: syn::LitStr = value.parse()?;
+                label = Some(lit.value());
+            }
+            Ok(())
+        });
+        if let Some(label) = label {
+            return label;
+        }
+    }
+    variant.ident.to_string()
+}
@@ -0,0 +1,197 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, LitStr, Token, Type};
+
+/// Parsed form of a `canyon_query!` invocation.
+///
+/// ```ignore
+/// canyon_query! {
+///     struct LeagueRow;
+///     sql: "SELECT id, name FROM leagues WHERE id = $1",
+///     params: [league_id],
+///     columns: { id: i32, name: String },
+/// }
+/// ```
+pub struct CanyonQuery {
+    result_ty: Ident,
+    sql: LitStr,
+    params: Vec<Expr>,
+    columns: Vec<(Ident, Type)>,
+}
+
+impl Parse for CanyonQuery {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![struct]>()?;
+        let result_ty: Ident = input.parse()?;
+        input.parse::<Token![;]>()?;
+
+        keyword(input, "sql")?;
+        let sql: LitStr = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        keyword(input, "params")?;
+        let params_content;
+        syn::bracketed!(params_content in input);
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(&params_content)?
+            .into_iter()
+            .collect();
+        input.parse::<Token![,]>()?;
+
+        keyword(input, "columns")?;
+        let columns_content;
+        syn::braced!(columns_content in input);
+        let columns = Punctuated::<ColumnDecl, Token![,]>::parse_terminated(&columns_content)?
+            .into_iter()
+            .map(|c| (c.name, c.ty))
+            .collect();
+        let _ = input.parse::<Token![,]>();
+
+        Ok(CanyonQuery {
+            result_ty,
+            sql,
+            params,
+            columns,
+        })
+    }
+}
+
+struct ColumnDecl {
+    name: Ident,
+    ty: Type,
+}
+
+impl Parse for ColumnDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(ColumnDecl { name, ty })
+    }
+}
+
+/// Consumes an expected `ident:` header, erroring with a helpful message.
+fn keyword(input: ParseStream, expected: &str) -> syn::Result<()> {
+    let ident: Ident = input.parse()?;
+    if ident != expected {
+        return Err(syn::Error::new(ident.span(), format!("expected `{expected}`")));
+    }
+    input.parse::<Token![:]>()?;
+    Ok(())
+}
+
+/// Returns the highest `$n` positional placeholder index in the SQL statement
+/// (0 when it has none).
+fn placeholder_count(sql: &str) -> usize {
+    let mut max = 0usize;
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'$' {
+            let mut j = i + 1;
+            let mut n = 0usize;
+            let mut saw_digit = false;
+            while j < bytes.len() && bytes[j].is_ascii_digit() {
+                n = n * 10 + (bytes[j] - b'0') as usize;
+                saw_digit = true;
+                j += 1;
+            }
+            if saw_digit {
+                max = max.max(n);
+            }
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+    max
+}
+
+/// Generates the TokenStream for a `canyon_query!` invocation: a result struct
+/// plus an async `query`/`query_datasource` pair that binds each argument
+/// through its [`QueryParameters`] impl and maps each declared column through
+/// [`RowOperations::get`].
+///
+/// Unlike the runtime string building in `generate_update_query_tokens`, the
+/// placeholder count is checked against the number of parameters here, at macro
+/// expansion time, and every parameter is type-checked against the existing
+/// `QueryParameters` impls before the query can compile.
+pub fn generate_canyon_query_tokens(parsed: &CanyonQuery) -> TokenStream {
+    let CanyonQuery {
+        result_ty,
+        sql,
+        params,
+        columns,
+    } = parsed;
+
+    let declared = placeholder_count(&sql.value());
+    if declared != params.len() {
+        return syn::Error::new(
+            sql.span(),
+            format!(
+                "SQL declares {declared} positional placeholder(s) but {} parameter(s) were supplied",
+                params.len()
+            ),
+        )
+        .to_compile_error();
+    }
+
+    let field_defs = columns.iter().map(|(name, ty)| quote! { pub #name: #ty });
+    let field_reads_pg = columns.iter().map(|(name, ty)| {
+        let col = name.to_string();
+        quote! { #name: canyon_sql::bounds::RowOperations::get::<#ty>(&row, #col) }
+    });
+    let field_reads_mssql = field_reads_pg.clone();
+
+    quote! {
+        #[derive(Debug, Clone)]
+        pub struct #result_ty {
+            #(#field_defs),*
+        }
+
+        impl #result_ty {
+            /// Runs the compile-time checked statement against the default datasource.
+            pub async fn query()
+                -> Result<Vec<#result_ty>, Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                #result_ty::query_datasource("").await
+            }
+
+            /// Runs the compile-time checked statement against `datasource_name`.
+            pub async fn query_datasource(datasource_name: &str)
+                -> Result<Vec<#result_ty>, Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                // Each argument is coerced into `&dyn QueryParameters`, so a type
+                // without a `QueryParameters` impl fails to compile right here.
+                let params: &[&dyn canyon_sql::bounds::QueryParameters<'_>] = &[
+                    #(&#params),*
+                ];
+
+                let result = <#result_ty as canyon_sql::canyon_crud::crud::Transaction<#result_ty>>::query(
+                    #sql, params, datasource_name
+                ).await?;
+
+                // The generated `RowMapper` below lets `get_entities` deserialize
+                // straight into the result struct, using the same name-based
+                // column reads for both backends.
+                Ok(result.get_entities())
+            }
+        }
+
+        // `Transaction::<#result_ty>::query` requires `#result_ty: RowMapper`, so
+        // the macro supplies it, mapping each column by name through its declared
+        // type's `FromSql` bridge.
+        impl canyon_sql::canyon_crud::mapper::RowMapper<#result_ty> for #result_ty {
+            fn deserialize_postgresql(row: &canyon_sql::canyon_connection::tokio_postgres::Row) -> #result_ty {
+                let row = row as &dyn canyon_sql::bounds::Row;
+                #result_ty { #(#field_reads_pg),* }
+            }
+
+            fn deserialize_sqlserver(row: &canyon_sql::canyon_connection::tiberius::Row) -> #result_ty {
+                let row = row as &dyn canyon_sql::bounds::Row;
+                #result_ty { #(#field_reads_mssql),* }
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+/// The parent side of a `#[foreign_key]` relation, as seen from the *child*
+/// entity currently being derived: the child's own Rust type and table, plus the
+/// column on the child that holds the foreign key. A derive only ever sees the
+/// struct it is applied to, so this is built from that struct's own
+/// `#[foreign_key]` annotation(s) — there is no crate-wide scan.
+pub struct ReverseRelation {
+    pub child_ty: Ident,
+    pub child_table: String,
+    pub fk_column: String,
+}
+
+/// Generates the reverse (parent -> children) foreign-key navigation methods.
+///
+/// The child -> parent direction is already covered by `search_league()` /
+/// `Tournament::belongs_to(&lec)`. This emits the "one" side of the relation on
+/// the *referenced* entity, e.g. `league.search_related_tournaments().await ->
+/// Vec<Tournament>`. Because a derive only sees its own struct, the method is
+/// emitted while deriving the *child* (`Tournament`): its `#[foreign_key]`
+/// annotation names the parent type and primary key, so the child's expansion
+/// can attach an `impl League { .. }` block in the same crate. Each method runs
+/// `SELECT * FROM <child_table> WHERE <fk_column> = <self.pk>`, giving proper
+/// one-to-many navigation without hand-writing the reverse query.
+pub fn generate_reverse_foreign_key_tokens(
+    parent_ty: &Ident,
+    primary_key: &str,
+    relations: &[ReverseRelation],
+) -> TokenStream {
+    let pk_ident = format_ident!("{}", primary_key);
+
+    let methods = relations.iter().map(|relation| {
+        let ReverseRelation {
+            child_ty,
+            child_table,
+            fk_column,
+        } = relation;
+
+        let method = format_ident!("search_related_{}s", child_table);
+        let stmt = format!("SELECT * FROM {child_table} WHERE {fk_column} = $1");
+
+        quote! {
+            /// Navigates the reverse side of the foreign-key relation, returning
+            /// every related record of the child entity.
+            pub async fn #method(&self) -> Vec<#child_ty> {
+                let params: &[&dyn canyon_sql::bounds::QueryParameters<'_>] = &[&self.#pk_ident];
+
+                let result = <#child_ty as canyon_sql::canyon_crud::crud::Transaction<#child_ty>>::query(
+                    #stmt, params, ""
+                ).await
+                    .expect("Failed to run the reverse foreign-key lookup");
+
+                result.get_entities()
+            }
+        }
+    });
+
+    quote! {
+        impl #parent_ty {
+            #(#methods)*
+        }
+    }
+}
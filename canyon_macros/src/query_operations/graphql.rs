@@ -0,0 +1,143 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::utils::macro_tokens::MacroTokens;
+
+/// Generates, behind the `graphql` feature flag, the `async-graphql` object
+/// definition and root query fields for a `#[canyon_entity]` type.
+///
+/// Canyon already knows every field (via the generated `<Type>Fields` enum) and
+/// the primary key, which is enough to expose a read API: an object type with a
+/// resolver for every column whose primary key carries the federation
+/// `#[graphql(key)]` directive so multiple Canyon services compose into one
+/// supergraph, plus a root `<type>(id)` -> single and
+/// `<type>s(limit, offset)` -> list backed by the QueryBuilder.
+///
+/// A column carrying `#[foreign_key(table = "...")]` is not exposed as a raw key
+/// but as a relation resolver returning the parent object, backed by the
+/// generated child -> parent `search_<table>()` method (e.g. `Tournament.league`
+/// -> `Option<League>`). The reverse (one-to-many) side is reachable through the
+/// generated `search_related_<child>s()` CRUD method on the parent; it is not a
+/// GraphQL field here because `async-graphql` admits a single `#[Object]` impl
+/// per type and the parent's object is generated from its own derive.
+pub fn generate_graphql_tokens(
+    macro_data: &MacroTokens,
+    table_schema_data: &String,
+) -> TokenStream {
+    let ty = macro_data.ty;
+    let query_ty = format_ident!("{}Query", ty);
+
+    let single_fn = format_ident!("{}", table_schema_data.to_lowercase());
+    let list_fn = format_ident!("{}s", table_schema_data.to_lowercase());
+
+    let primary_key = macro_data
+        .get_primary_key_annotation()
+        .unwrap_or_else(|| "id".to_owned());
+
+    // One resolver per column, returning a clone of the stored value so the
+    // object exposes every field of the entity. A foreign-key column resolves to
+    // its parent object through the generated `search_<table>()` method instead
+    // of exposing the raw key; the primary key carries the federation
+    // `#[graphql(key)]` directive, letting several Canyon services compose into a
+    // single supergraph that can reference this entity.
+    let field_resolvers = macro_data.fields.iter().filter_map(|field| {
+        let ident = field.ident.as_ref()?;
+        let field_ty = &field.ty;
+
+        if let Some(table) = foreign_key_table(field) {
+            let related_ty = format_ident!("{}", pascal_case(&table));
+            let search = format_ident!("search_{}", table);
+            return Some(quote! {
+                /// Resolves the parent side of the foreign-key relation.
+                async fn #ident(&self) -> Option<#related_ty> {
+                    self.#search().await
+                }
+            });
+        }
+
+        let is_key = *ident == primary_key;
+        let attr = if is_key {
+            quote! { #[graphql(key)] }
+        } else {
+            quote! {}
+        };
+        Some(quote! {
+            #attr
+            async fn #ident(&self) -> #field_ty {
+                self.#ident.clone()
+            }
+        })
+    });
+
+    quote! {
+        #[cfg(feature = "graphql")]
+        #[async_graphql::Object]
+        impl #ty {
+            #(#field_resolvers)*
+        }
+
+        #[cfg(feature = "graphql")]
+        #[derive(Default)]
+        pub struct #query_ty;
+
+        #[cfg(feature = "graphql")]
+        #[async_graphql::Object]
+        impl #query_ty {
+            /// Resolves a single entity by primary key.
+            async fn #single_fn(&self, id: i32) -> Option<#ty> {
+                <#ty as canyon_sql::canyon_crud::crud::CrudOperations<#ty>>::find_by_id(id).await
+            }
+
+            /// Resolves a paginated list of entities through the QueryBuilder.
+            async fn #list_fn(&self, limit: Option<i64>, offset: Option<i64>) -> Vec<#ty> {
+                let mut builder = <#ty as canyon_sql::canyon_crud::crud::CrudOperations<#ty>>::find_all_query();
+                if let Some(limit) = limit {
+                    builder = builder.limit(limit);
+                }
+                if let Some(offset) = offset {
+                    builder = builder.offset(offset);
+                }
+                builder.query().await
+            }
+        }
+    }
+}
+
+/// Reads the `table = "..."` target of a field's `#[foreign_key(...)]`
+/// annotation, returning `None` for a plain column. Mirrors how the CRUD derive
+/// reads the same annotation to emit `search_<table>()`.
+fn foreign_key_table(field: &syn::Field) -> Option<String> {
+    for attr in &field.attrs {
+        if !attr.path().is_ident("foreign_key") {
+            continue;
+        }
+        let mut table = None;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                let value = meta.value()?;
+                let lit: syn::LitStr = value.parse()?;
+                table = Some(lit.value());
+            }
+            Ok(())
+        });
+        if table.is_some() {
+            return table;
+        }
+    }
+    None
+}
+
+/// Turns a snake_case table name into the PascalCase entity type it maps to
+/// (`league` -> `League`), matching Canyon's table <-> type convention.
+fn pascal_case(table: &str) -> String {
+    table
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,169 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::utils::macro_tokens::MacroTokens;
+
+/// Generates the TokenStream for the `upsert()` / `insert_or_update()` operation.
+///
+/// On Postgres this emits
+/// `INSERT INTO <table> (...) VALUES (...) ON CONFLICT (<pk>) DO UPDATE SET
+/// <col> = EXCLUDED.<col>, ...`, updating every non-conflict column from the
+/// instance. The conflict target is the primary key, so the inserted tuple must
+/// carry the primary-key value; repeated upserts of the same record update in
+/// place instead of creating duplicates.
+pub fn generate_upsert_tokens(
+    macro_data: &MacroTokens,
+    table_schema_data: &String,
+) -> TokenStream {
+    let ty = macro_data.ty;
+
+    let conflict_target = macro_data
+        .get_primary_key_annotation()
+        .expect("`upsert` requires a #[primary_key] annotation to use as the ON CONFLICT target");
+
+    // Every column, *including* the primary key: `ON CONFLICT (<pk>)` can only
+    // fire when the inserted tuple actually supplies the primary-key value, so
+    // (unlike the key-generating `insert`) the pk is not stripped here.
+    let insert_columns = macro_data.get_column_names();
+    let columns_str = insert_columns.join(", ");
+    let columns_count = insert_columns.len();
+
+    // On a collision every column except the conflict target itself is refreshed
+    // from the proposed row.
+    let set_assignments = insert_columns
+        .iter()
+        .filter(|c| **c != conflict_target)
+        .map(|c| format!("{c} = EXCLUDED.{c}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // The bind values, in the same column order, are every struct field.
+    let field_idents = macro_data.get_struct_fields();
+
+    quote! {
+        /// Inserts the instance, or updates the conflicting row in place when a
+        /// record with the same conflict target already exists.
+        async fn upsert(&self)
+            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+        {
+            let placeholders = (1..=#columns_count)
+                .map(|i| format!("${}", i))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let stmt = format!(
+                "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT ({}) DO UPDATE SET {}",
+                #table_schema_data, #columns_str, placeholders, #conflict_target, #set_assignments
+            );
+
+            let values: &[&dyn canyon_sql::bounds::QueryParameters<'_>] = &[
+                #(&self.#field_idents),*
+            ];
+
+            <#ty as canyon_sql::canyon_crud::crud::Transaction<#ty>>::query(
+                &stmt, values, ""
+            ).await.map(|_| ())
+        }
+
+        /// Alias for [`upsert`], spelled out for readers who prefer the verb.
+        async fn insert_or_update(&self)
+            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+        {
+            self.upsert().await
+        }
+    }
+}
+
+/// Generates the TokenStream for the `multi_insert()` CRUD operation.
+///
+/// Unlike the per-row `insert()`, which fires one round-trip per instance, this
+/// builds a single `INSERT INTO <table> (...) VALUES ($1,$2,...),($6,$7,...),...`
+/// statement binding every row's parameters, executes it once, and reads the
+/// generated keys back (via `RETURNING <pk>` on Postgres), writing each new id
+/// into the matching instance.
+pub fn generate_multi_insert_tokens(
+    macro_data: &MacroTokens,
+    table_schema_data: &String,
+) -> TokenStream {
+    let ty = macro_data.ty;
+
+    // Columns minus the primary key, which is database-generated.
+    let insert_columns = macro_data.get_column_names_pk_parsed();
+    let columns_str = insert_columns.join(", ");
+    let columns_per_row = insert_columns.len();
+
+    let Some(primary_key) = macro_data.get_primary_key_annotation() else {
+        // Without a primary key there's nothing to read back, so the batch insert
+        // mirrors `update`'s guard and refuses rather than silently misbehaving.
+        return quote! {
+            async fn multi_insert<'a>(instances: &'a mut [#ty])
+                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            {
+                let _ = instances;
+                Err(
+                    std::io::Error::new(
+                        std::io::ErrorKind::Unsupported,
+                        "You can't use 'multi_insert' on a CanyonEntity without a \
+                        #[primary_key] annotation, as there is no key to read back."
+                    ).into_inner().unwrap()
+                )
+            }
+        };
+    };
+
+    // Every non-pk field, in declaration order, as `&instance.field`.
+    let insert_fields = macro_data.get_column_names_pk_parsed();
+    let field_idents = insert_fields
+        .iter()
+        .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()));
+
+    let pk_ident = syn::Ident::new(&primary_key, proc_macro2::Span::call_site());
+
+    quote! {
+        /// Inserts every instance in a single statement and writes the
+        /// database-generated primary key back into each one.
+        async fn multi_insert<'a>(instances: &'a mut [#ty])
+            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+        {
+            if instances.is_empty() {
+                return Ok(());
+            }
+
+            // Build one `($n,$n+1,...)` tuple of placeholders per instance.
+            let mut placeholder_groups: Vec<String> = Vec::with_capacity(instances.len());
+            let mut param_index = 1;
+            for _ in instances.iter() {
+                let group = (0..#columns_per_row)
+                    .map(|_| { let p = format!("${}", param_index); param_index += 1; p })
+                    .collect::<Vec<_>>()
+                    .join(",");
+                placeholder_groups.push(format!("({})", group));
+            }
+
+            let stmt = format!(
+                "INSERT INTO {} ({}) VALUES {} RETURNING {}",
+                #table_schema_data, #columns_str, placeholder_groups.join(","), #primary_key
+            );
+
+            // Flatten every row's bind parameters, preserving column order.
+            let mut params: Vec<&dyn canyon_sql::bounds::QueryParameters<'_>> = Vec::new();
+            for instance in instances.iter() {
+                #( params.push(&instance.#field_idents); )*
+            }
+
+            let result = <#ty as canyon_sql::canyon_crud::crud::Transaction<#ty>>::query(
+                &stmt, params.as_slice(), ""
+            ).await?;
+
+            // The RETURNING rows come back in insertion order; copy each new id
+            // back into its originating instance. `try_get` surfaces a decode
+            // failure as an error instead of panicking, matching the fallible
+            // `RowOperations` contract.
+            for (instance, row) in instances.iter_mut().zip(result.get_rows().iter()) {
+                instance.#pk_ident = canyon_sql::bounds::RowOperations::try_get(row, #primary_key)?;
+            }
+
+            Ok(())
+        }
+    }
+}